@@ -0,0 +1,96 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// A version control system gitfindr knows how to detect a repository for.
+///
+/// Implement this trait and add an instance to [`registry`] to let gitfindr
+/// track repositories for a VCS other than git.
+pub trait Backend: Sync + Send {
+    /// The name under which this backend's matches are recorded in `RepoData`.
+    fn name(&self) -> &'static str;
+
+    /// The file/directory entry that marks a directory as one of this backend's
+    /// repository roots (e.g. `.git`, `.hg`).
+    fn marker(&self) -> &'static str;
+
+    /// Returns true if `path` looks like the root of a repository for this backend.
+    fn detect(&self, path: &Path) -> bool {
+        match fs::read_dir(path) {
+            Ok(dir_it) => dir_it
+                .flatten()
+                .any(|entry| entry.file_name().eq(self.marker())),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Detects git repositories by the presence of a `.git` entry.
+pub struct GitBackend;
+
+impl Backend for GitBackend {
+    fn name(&self) -> &'static str {
+        "git"
+    }
+
+    fn marker(&self) -> &'static str {
+        ".git"
+    }
+}
+
+/// Detects Mercurial repositories by the presence of an `.hg` entry.
+pub struct MercurialBackend;
+
+impl Backend for MercurialBackend {
+    fn name(&self) -> &'static str {
+        "hg"
+    }
+
+    fn marker(&self) -> &'static str {
+        ".hg"
+    }
+}
+
+/// The set of backends gitfindr consults when looking for a repository.
+///
+/// Built once and reused: constructing this list allocates a `Box<dyn Backend>`
+/// per entry, and this is called from the hot per-directory/per-entry scanning
+/// paths, so it's cached behind a `OnceLock` rather than rebuilt on every call.
+///
+/// Third parties wanting to support another VCS can follow the same pattern
+/// as [`GitBackend`]/[`MercurialBackend`] and add their backend here.
+pub fn registry() -> &'static [Box<dyn Backend>] {
+    static REGISTRY: OnceLock<Vec<Box<dyn Backend>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| vec![Box::new(GitBackend), Box::new(MercurialBackend)])
+}
+
+/// Returns the name of the first registered backend that detects a repository at `path`.
+pub fn detect(path: &Path) -> Option<&'static str> {
+    registry()
+        .iter()
+        .find(|backend| backend.detect(path))
+        .map(|backend| backend.name())
+}
+
+/// Returns the name of the first registered backend whose marker appears among
+/// `entry_names`, without reading `path` from disk again.
+///
+/// Use this when the caller has already listed a directory's entries (e.g. while
+/// walking it for subdirectories), to avoid a redundant `read_dir` per backend.
+pub fn detect_from_entries<'a, I>(entry_names: I) -> Option<&'static str>
+where
+    I: IntoIterator<Item = &'a OsStr>,
+{
+    let entry_names: Vec<&OsStr> = entry_names.into_iter().collect();
+    registry()
+        .iter()
+        .find(|backend| entry_names.iter().any(|name| name.eq(&backend.marker())))
+        .map(|backend| backend.name())
+}
+
+/// Returns true if `entry_name` is the marker directory/file for any registered backend
+/// (e.g. `.git`, `.hg`), so directory walks can skip descending into it.
+pub fn is_marker(entry_name: &OsStr) -> bool {
+    registry().iter().any(|backend| entry_name.eq(backend.marker()))
+}
@@ -10,6 +10,8 @@ const REPO_ALREADY_EXISTS: &str = "The given repository already exists.";
 const REPO_DOES_NOT_EXIST: &str = "The given repository does not exist";
 /// Error message for when gitfindr cannot extract the repo name from the path
 const INVALID_NAME_IN_PATH: &str = "Could not extract repo name from path";
+/// Error message for when a `git` invocation fails or `git` is missing
+const GIT_COMMAND_FAILED: &str = "Failed to run git (is it installed and is this a valid repository?)";
 
 #[derive(Debug)]
 pub struct NotARepositoryError;
@@ -52,4 +54,15 @@ impl Display for RepoNameExtractError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { write!(f, "{}", INVALID_NAME_IN_PATH) }
 }
 
-impl Error for RepoNameExtractError {}
\ No newline at end of file
+impl Error for RepoNameExtractError {}
+
+#[derive(Debug)]
+pub struct GitCommandError;
+
+impl Display for GitCommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", GIT_COMMAND_FAILED)
+    }
+}
+
+impl Error for GitCommandError {}
\ No newline at end of file
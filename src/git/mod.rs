@@ -0,0 +1,158 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::err::GitCommandError;
+use crate::GFResult;
+
+/// Holds a snapshot of a repository's status relative to its upstream
+#[derive(Debug)]
+pub struct RepoStatus {
+    pub branch: String,
+    pub staged: usize,
+    pub unstaged: usize,
+    pub untracked: usize,
+    pub unmerged: usize,
+    pub ahead: usize,
+    pub behind: usize,
+    pub changed_paths: Vec<String>,
+}
+
+impl RepoStatus {
+    pub fn is_dirty(&self) -> bool {
+        self.staged > 0 || self.unstaged > 0 || self.untracked > 0 || self.unmerged > 0
+    }
+}
+
+/// Runs `git clone --recursive <url> <dest>`.
+///
+/// # Arguments
+/// * `url` - The remote URL to clone.
+/// * `dest` - The directory to clone into.
+///
+/// # Returns
+/// A result object with the Ok variant on success, or a `GitCommandError` on failure.
+pub fn clone<P: AsRef<Path>>(url: &str, dest: P) -> GFResult<()> {
+    let output = Command::new("git")
+        .arg("clone")
+        .arg("--recursive")
+        .arg(url)
+        .arg(dest.as_ref())
+        .output()
+        .map_err(|_| GitCommandError)?;
+
+    if !output.status.success() {
+        return Err(Box::new(GitCommandError));
+    }
+
+    Ok(())
+}
+
+/// Runs `git status --porcelain=v2 --branch` and parses dirty counts, ahead/behind
+/// counts and changed file paths out of its output.
+///
+/// # Arguments
+/// * `path` - Path to the repository's directory.
+///
+/// # Returns
+/// A result object with the parsed `RepoStatus` on success, or a `GitCommandError` on failure.
+pub fn status_porcelain<P: AsRef<Path>>(path: P) -> GFResult<RepoStatus> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(path.as_ref())
+        .arg("status")
+        .arg("--porcelain=v2")
+        .arg("--branch")
+        .output()
+        .map_err(|_| GitCommandError)?;
+
+    if !output.status.success() {
+        return Err(Box::new(GitCommandError));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut branch = String::new();
+    let mut staged = 0;
+    let mut unstaged = 0;
+    let mut untracked = 0;
+    let mut unmerged = 0;
+    let mut ahead = 0;
+    let mut behind = 0;
+    let mut changed_paths = Vec::new();
+
+    for line in stdout.lines() {
+        if let Some(rest) = line.strip_prefix("# branch.head ") {
+            branch = rest.to_string();
+        } else if let Some(rest) = line.strip_prefix("# branch.ab ") {
+            for part in rest.split_whitespace() {
+                if let Some(n) = part.strip_prefix('+') {
+                    ahead = n.parse().unwrap_or(0);
+                } else if let Some(n) = part.strip_prefix('-') {
+                    behind = n.parse().unwrap_or(0);
+                }
+            }
+        } else if let Some(rest) = line.strip_prefix("1 ") {
+            // ordinary changed entry: "<XY> <sub> <mH> <mI> <mW> <hH> <hI> <path>"
+            count_xy(rest, &mut staged, &mut unstaged);
+            if let Some(path) = skip_fields(rest, 7) {
+                changed_paths.push(path.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("2 ") {
+            // renamed/copied entry: "... <X><score> <path>\t<origPath>"
+            count_xy(rest, &mut staged, &mut unstaged);
+            if let Some(field) = skip_fields(rest, 8) {
+                let path = field.split('\t').next().unwrap_or(field);
+                changed_paths.push(path.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("u ") {
+            // unmerged/conflict entry: "<XY> <sub> <m1> <m2> <m3> <mW> <h1> <h2> <h3> <path>"
+            unmerged += 1;
+            if let Some(path) = skip_fields(rest, 9) {
+                changed_paths.push(path.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("? ") {
+            untracked += 1;
+            changed_paths.push(rest.to_string());
+        }
+    }
+
+    Ok(RepoStatus {
+        branch,
+        staged,
+        unstaged,
+        untracked,
+        unmerged,
+        ahead,
+        behind,
+        changed_paths,
+    })
+}
+
+/// Skips `n` space-separated leading fields in `rest` and returns what remains.
+///
+/// Used to pull the trailing path field(s) out of a porcelain v2 changed-entry
+/// line without splitting on every space, since a path may itself contain spaces.
+fn skip_fields(rest: &str, n: usize) -> Option<&str> {
+    let mut remainder = rest;
+    for _ in 0..n {
+        let space = remainder.find(' ')?;
+        remainder = &remainder[space + 1..];
+    }
+    Some(remainder)
+}
+
+/// Pulls the `XY` status code out of a porcelain v2 changed-entry line and bumps
+/// the staged/unstaged counters accordingly.
+fn count_xy(rest: &str, staged: &mut usize, unstaged: &mut usize) {
+    if let Some(xy) = rest.split_whitespace().next() {
+        let mut chars = xy.chars();
+        let x = chars.next().unwrap_or('.');
+        let y = chars.next().unwrap_or('.');
+        if x != '.' {
+            *staged += 1;
+        }
+        if y != '.' {
+            *unstaged += 1;
+        }
+    }
+}
@@ -1,28 +1,41 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::error::Error;
-use std::fs;
+use std::ffi::OsString;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
 
 use clap::{App, Arg, SubCommand};
 use serde::{Deserialize, Serialize};
 
 use crate::err::{NotARepositoryError, RepoAlreadyExistsError, RepoDoesNotExistError, RepoNameExtractError};
 
+mod backend;
 mod err;
+mod git;
 
 /// Name of the config file
 const CONFIG_NAME: &str = "gitfnder";
-/// File extension for git repo
-const GIT_FILE: &str = ".git";
-
 type GFResult<T> = Result<T, Box<dyn Error>>;
 
+/// Backend name used for repos tracked before backend detection existed
+fn default_backend() -> String {
+    "git".to_string()
+}
+
 /// Holds data about a repository
 #[derive(Debug, Serialize, Deserialize)]
 struct RepoData {
     name: String,
     path: PathBuf,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default = "default_backend")]
+    backend: String,
+    #[serde(default)]
+    remote: Option<String>,
 }
 
 impl RepoData {
@@ -35,10 +48,54 @@ impl RepoData {
     ///
     ///  # Returns
     /// A result object with the RepoData on success, or an Err variant with a Box\<dyn Error\> on failure.
+    #[allow(dead_code)]
     fn new<P: Into<PathBuf>>(name: &str, path: P) -> Self {
         RepoData {
             name: name.to_string(),
             path: path.into(),
+            tags: Vec::new(),
+            backend: default_backend(),
+            remote: None,
+        }
+    }
+
+    /// Creates new data object for a repo, tagged with the given categories and
+    /// recording which VCS backend matched it.
+    ///
+    /// # Arguments
+    /// * `name` - A string slice representing the repositories name.
+    /// * `path` - The absolute path to the repository's directory.
+    /// * `tags` - The categories/tags to associate with this repo.
+    /// * `backend` - The name of the VCS backend that detected this repo.
+    ///
+    /// # Returns
+    /// A new `RepoData` carrying the given tags and backend.
+    fn new_with_tags<P: Into<PathBuf>>(name: &str, path: P, tags: Vec<String>, backend: &str) -> Self {
+        RepoData {
+            name: name.to_string(),
+            path: path.into(),
+            tags,
+            backend: backend.to_string(),
+            remote: None,
+        }
+    }
+
+    /// Creates new data object for a repo that was just cloned from a remote.
+    ///
+    /// # Arguments
+    /// * `name` - A string slice representing the repositories name.
+    /// * `path` - The absolute path to the repository's directory.
+    /// * `remote` - The URL the repo was cloned from.
+    ///
+    /// # Returns
+    /// A new `RepoData` tracking the given remote, using the `git` backend.
+    fn new_cloned<P: Into<PathBuf>>(name: &str, path: P, remote: &str) -> Self {
+        RepoData {
+            name: name.to_string(),
+            path: path.into(),
+            tags: Vec::new(),
+            backend: default_backend(),
+            remote: Some(remote.to_string()),
         }
     }
 
@@ -78,6 +135,9 @@ impl Default for RepoData {
         RepoData {
             name: String::new(),
             path: PathBuf::new(),
+            tags: Vec::new(),
+            backend: default_backend(),
+            remote: None,
         }
     }
 }
@@ -118,6 +178,44 @@ impl GitFindrConfig {
     fn get_repo(&self, name: &str) -> Option<&RepoData> {
         self.repos.get(name)
     }
+
+    /// Adds a tag to a tracked repo, keeping its tag list deduplicated
+    ///
+    /// # Arguments
+    /// * `name` - The name of the tracked repo to tag.
+    /// * `tag` - The tag/category to add.
+    ///
+    /// # Returns
+    /// Result Ok variant if the repo exists and the tag was added.
+    fn add_tag(&mut self, name: &str, tag: &str) -> GFResult<()> {
+        match self.repos.get_mut(name) {
+            Some(repo) => {
+                if !repo.tags.iter().any(|t| t == tag) {
+                    repo.tags.push(tag.to_string());
+                }
+                Ok(())
+            }
+            None => Err(Box::new(RepoDoesNotExistError)),
+        }
+    }
+
+    /// Removes a tag from a tracked repo
+    ///
+    /// # Arguments
+    /// * `name` - The name of the tracked repo to untag.
+    /// * `tag` - The tag/category to remove.
+    ///
+    /// # Returns
+    /// Result Ok variant if the repo exists and the tag was removed.
+    fn remove_tag(&mut self, name: &str, tag: &str) -> GFResult<()> {
+        match self.repos.get_mut(name) {
+            Some(repo) => {
+                repo.tags.retain(|t| t != tag);
+                Ok(())
+            }
+            None => Err(Box::new(RepoDoesNotExistError)),
+        }
+    }
 }
 
 impl Default for GitFindrConfig {
@@ -128,71 +226,246 @@ impl Default for GitFindrConfig {
     }
 }
 
-/// Validates that the given string slice is a valid path that points to a repository.
+/// Validates that the given path points to a repository for one of the registered
+/// VCS backends.
 ///
 /// # Arguments
 /// * `path_str` - A string slice representing the absolute path to a repository
 ///
 /// # Returns
-/// A result object with an empty Ok variant on success, or an Err variant with a Box\<dyn Error\> on failure.
-fn validate_repo<P: AsRef<Path>>(path: P) -> self::GFResult<()> {
-    match fs::read_dir(path) {
-        Ok(dir_it) => {
-            for entry_res in dir_it {
-                match entry_res {
-                    Ok(entry) => {
-                        if entry.file_name().eq(GIT_FILE) {
-                            return Result::Ok(());
-                        }
-                    }
-                    Err(err) => return Result::Err(Box::new(err)),
-                }
+/// A result object with the matched backend's name on success, or an Err variant
+/// with a Box\<dyn Error\> on failure.
+fn validate_repo<P: AsRef<Path>>(path: P) -> self::GFResult<&'static str> {
+    match backend::detect(path.as_ref()) {
+        Some(name) => Ok(name),
+        None => Err(Box::new(NotARepositoryError)),
+    }
+}
+
+/// Infers a repo name from the last path segment of a remote URL, stripping a
+/// trailing `.git` suffix if present.
+///
+/// # Arguments
+/// * `url` - The remote URL to extract a name from.
+///
+/// # Returns
+/// A result object with the inferred name on success, or a `RepoNameExtractError` on failure.
+fn repo_name_from_url(url: &str) -> self::GFResult<String> {
+    let segment = url
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .ok_or(RepoNameExtractError)?;
+
+    let name = segment.strip_suffix(".git").unwrap_or(segment);
+
+    if name.is_empty() {
+        return Err(Box::new(RepoNameExtractError));
+    }
+
+    Ok(name.to_string())
+}
+
+/// The shared state behind a [`ScanQueue`]: the directories still left to visit,
+/// and a count of workers currently scanning one (so the queue knows when the
+/// walk is actually done, rather than just momentarily empty).
+struct ScanState {
+    queue: VecDeque<PathBuf>,
+    active: usize,
+}
+
+/// A single FIFO queue of directories left to scan, shared by all the worker
+/// threads in [`parse_directory`]. Workers that find the queue empty wait on
+/// the condvar instead of busy-spinning, and are woken as soon as another
+/// worker discovers more subdirectories or finishes its last one.
+struct ScanQueue {
+    state: Mutex<ScanState>,
+    cond: Condvar,
+}
+
+impl ScanQueue {
+    fn new(root: PathBuf) -> Self {
+        ScanQueue {
+            state: Mutex::new(ScanState {
+                queue: VecDeque::from([root]),
+                active: 0,
+            }),
+            cond: Condvar::new(),
+        }
+    }
+
+    /// Blocks until a directory is available to scan, returning `None` once
+    /// every worker is idle with nothing left queued (the walk is complete).
+    fn pop(&self) -> Option<PathBuf> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(dir) = state.queue.pop_front() {
+                state.active += 1;
+                return Some(dir);
             }
+            if state.active == 0 {
+                return None;
+            }
+            state = self.cond.wait(state).unwrap();
         }
-        Err(err) => return Result::Err(Box::new(err)),
     }
-    Result::Err(Box::new(NotARepositoryError))
-}
 
-//TODO does rayon have parallel iterators for directories?
-fn parse_directory<P: Into<PathBuf>>(dir_path: P) -> self::GFResult<Vec<RepoData>> {
-    let mut repos: Vec<RepoData> = Vec::new();
-    let mut dir_paths: Vec<PathBuf> = Vec::new();
+    /// Queues newly discovered subdirectories and marks the current directory
+    /// done, waking any workers waiting on `pop`.
+    fn push_and_finish(&self, subdirs: Vec<PathBuf>) {
+        let mut state = self.state.lock().unwrap();
+        state.queue.extend(subdirs);
+        state.active -= 1;
+        self.cond.notify_all();
+    }
+}
 
-    dir_paths.push(dir_path.into());
-    while let Some(dir) = dir_paths.pop() {
+/// Scans a single directory for subdirectories to walk next (skipping VCS
+/// internals such as `.git`/`.hg`) and, if it is itself a repo, pushes a
+/// `RepoData` into the shared collector.
+///
+/// # Returns
+/// The subdirectories discovered, to be queued by the caller.
+fn scan_one(dir: &Path, repos: &Mutex<Vec<RepoData>>) -> Vec<PathBuf> {
+    let mut entry_names = Vec::new();
+    let mut subdirs = Vec::new();
 
-        // Add directories inside this directory to list to scan
-        match dir.read_dir() {
-            Ok(mut dir_it) => {
-                while let Some(Ok(dir_entry)) = dir_it.next() {
-                    if let Ok(metadata) = dir_entry.metadata() {
+    match dir.read_dir() {
+        Ok(dir_it) => {
+            for entry in dir_it.flatten() {
+                let file_name = entry.file_name();
+                if !backend::is_marker(&file_name) {
+                    if let Ok(metadata) = entry.metadata() {
                         if metadata.is_dir() {
-                            dir_paths.push(dir_entry.path());
+                            subdirs.push(entry.path());
                         }
                     }
                 }
-            },
-            Err(err) => eprintln!("{}", err)
+                entry_names.push(file_name);
+            }
         }
+        Err(err) => eprintln!("{}", err),
+    }
 
-        // create RepoData if this directory is a repo
-        match validate_repo(&dir) {
-            Ok(_) => {
-                if let Some(os_str) = dir.file_stem() {
-                    if let Some(name) = os_str.to_str() {
-                        repos.push(RepoData::new(name, dir.clone()))
+    if let Some(backend_name) =
+        backend::detect_from_entries(entry_names.iter().map(OsString::as_os_str))
+    {
+        if let Some(os_str) = dir.file_stem() {
+            if let Some(name) = os_str.to_str() {
+                repos.lock().unwrap().push(RepoData::new_with_tags(
+                    name,
+                    dir.to_path_buf(),
+                    Vec::new(),
+                    backend_name,
+                ));
+            } else {
+                eprintln!("{}", RepoNameExtractError);
+            }
+        } else {
+            eprintln!("{}", RepoNameExtractError);
+        }
+    }
+
+    subdirs
+}
+
+/// Recursively scans a directory tree for repositories using a pool of `jobs`
+/// worker threads sharing one deque of directories left to visit.
+///
+/// # Arguments
+/// * `dir_path` - The root directory to scan.
+/// * `jobs` - The number of worker threads to scan with.
+///
+/// # Returns
+/// A result object with the discovered `RepoData`s. It is always `Ok`: directories
+/// that cannot be read and repos whose name cannot be extracted are reported on
+/// stderr and skipped.
+fn parse_directory<P: Into<PathBuf>>(dir_path: P, jobs: usize) -> self::GFResult<Vec<RepoData>> {
+    let scan_queue = Arc::new(ScanQueue::new(dir_path.into()));
+    let repos = Arc::new(Mutex::new(Vec::new()));
+
+    let jobs = jobs.max(1);
+    let mut workers = Vec::with_capacity(jobs);
+    for _ in 0..jobs {
+        let scan_queue = Arc::clone(&scan_queue);
+        let repos = Arc::clone(&repos);
+        workers.push(thread::spawn(move || {
+            while let Some(dir) = scan_queue.pop() {
+                let subdirs = scan_one(&dir, &repos);
+                scan_queue.push_and_finish(subdirs);
+            }
+        }));
+    }
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    Ok(Arc::try_unwrap(repos).unwrap().into_inner().unwrap())
+}
+
+/// Runs `cmd` (with `cmd_args`) in the working directory of every given repo, using
+/// a pool of `jobs` worker threads, and prints a per-repo success/failure summary.
+///
+/// # Arguments
+/// * `repos` - The `(name, path)` pairs to run the command in.
+/// * `cmd` - The program to spawn.
+/// * `cmd_args` - The arguments to pass to `cmd`.
+/// * `jobs` - The number of repos to run the command in concurrently.
+fn exec_on_repos(repos: Vec<(String, PathBuf)>, cmd: &str, cmd_args: &[String], jobs: usize) {
+    let queue = Arc::new(Mutex::new(VecDeque::from(repos)));
+    let successes = Arc::new(Mutex::new(0usize));
+    let failures = Arc::new(Mutex::new(0usize));
+    // Serializes the per-repo output below so concurrent workers can't interleave
+    // their stdout/stderr into an unattributable mess.
+    let print_lock = Arc::new(Mutex::new(()));
+
+    let jobs = jobs.max(1);
+    let mut workers = Vec::with_capacity(jobs);
+    for _ in 0..jobs {
+        let queue = Arc::clone(&queue);
+        let successes = Arc::clone(&successes);
+        let failures = Arc::clone(&failures);
+        let print_lock = Arc::clone(&print_lock);
+        let cmd = cmd.to_string();
+        let cmd_args = cmd_args.to_vec();
+        workers.push(thread::spawn(move || loop {
+            let (name, path) = match queue.lock().unwrap().pop_front() {
+                Some(item) => item,
+                None => return,
+            };
+
+            match Command::new(&cmd).args(&cmd_args).current_dir(&path).output() {
+                Ok(output) => {
+                    let _guard = print_lock.lock().unwrap();
+                    print!("{}", String::from_utf8_lossy(&output.stdout));
+                    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                    if output.status.success() {
+                        println!("[ok] {}", name);
+                        *successes.lock().unwrap() += 1;
                     } else {
-                        eprintln!("{}", RepoNameExtractError);
+                        println!("[fail] {} ({})", name, output.status);
+                        *failures.lock().unwrap() += 1;
                     }
-                } else {
-                    eprintln!("{}", RepoNameExtractError);
+                }
+                Err(err) => {
+                    let _guard = print_lock.lock().unwrap();
+                    println!("[fail] {} ({})", name, err);
+                    *failures.lock().unwrap() += 1;
                 }
             }
-            Err(_err) => {}//eprintln!("{}", err)
-        }
+        }));
+    }
+
+    for worker in workers {
+        let _ = worker.join();
     }
-    Ok(repos)
+
+    println!(
+        "{} succeeded, {} failed",
+        *successes.lock().unwrap(),
+        *failures.lock().unwrap()
+    );
 }
 
 fn main() {
@@ -226,8 +499,61 @@ fn main() {
                         .allow_hyphen_values(true)
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("tag")
+                        .long("tag")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .multiple(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("jobs")
+                        .long("jobs")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(false),
+                )
                 .help("When you add a directory possible containing multiple repositories."),
         )
+        .subcommand(
+            SubCommand::with_name("tag")
+                .help("Adds a tag/category to a tracked repo.")
+                .arg(
+                    Arg::with_name("name")
+                        .short("-n")
+                        .long("name")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("tag")
+                        .long("tag")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("untag")
+                .help("Removes a tag/category from a tracked repo.")
+                .arg(
+                    Arg::with_name("name")
+                        .short("-n")
+                        .long("name")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("tag")
+                        .long("tag")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(true),
+                ),
+        )
         .subcommand(
             SubCommand::with_name("remove")
                 .help("Removes a local git repo from being tracked.")
@@ -249,6 +575,13 @@ fn main() {
                         .allow_hyphen_values(true)
                         .takes_value(false)
                         .required(false),
+                )
+                .arg(
+                    Arg::with_name("tag")
+                        .long("tag")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(false),
                 ),
         )
         .subcommand(
@@ -269,6 +602,60 @@ fn main() {
                         .required(false),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("clone")
+                .help("Clones a remote repository and starts tracking it.")
+                .arg(
+                    Arg::with_name("url")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("path")
+                        .long("path")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("alias")
+                        .long("alias")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("exec")
+                .help("Runs a shell command in every tracked repo (or a filtered subset).")
+                .arg(
+                    Arg::with_name("name")
+                        .short("-n")
+                        .long("name")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("tag")
+                        .long("tag")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("jobs")
+                        .long("jobs")
+                        .allow_hyphen_values(true)
+                        .takes_value(true)
+                        .required(false),
+                )
+                .arg(
+                    Arg::with_name("cmd")
+                        .multiple(true)
+                        .last(true)
+                        .required(true),
+                ),
+        )
         .get_matches();
 
     match matches.subcommand() {
@@ -276,9 +663,18 @@ fn main() {
             if args.is_present("-d") {
                 match args.value_of("-d") {
                     Some(dir_str) => {
-                        match parse_directory(dir_str) {
-                            Ok(repos) => {
-                                for repo in repos {
+                        let tags: Vec<String> = args
+                            .values_of("tag")
+                            .map(|vals| vals.map(str::to_string).collect())
+                            .unwrap_or_default();
+                        let jobs: usize = args
+                            .value_of("jobs")
+                            .and_then(|j| j.parse().ok())
+                            .unwrap_or(4);
+                        match parse_directory(dir_str, jobs) {
+                            Ok(mut repos) => {
+                                for mut repo in repos.drain(..) {
+                                    repo.tags = tags.clone();
                                     match config.add_repo(repo) {
                                         _ => {}
                                     }
@@ -290,9 +686,13 @@ fn main() {
                     None => eprintln!("User tried to scan a dir for repos, but not dir was given."),
                 }
             } else {
+                let tags: Vec<String> = args
+                    .values_of("tag")
+                    .map(|vals| vals.map(str::to_string).collect())
+                    .unwrap_or_default();
                 match (args.value_of("alias"), args.value_of("path")) {
                     (Some(alias), Some(path)) => match validate_repo(path) {
-                        Ok(_) => match config.add_repo(RepoData::new(alias, path)) {
+                        Ok(backend_name) => match config.add_repo(RepoData::new_with_tags(alias, path, tags, backend_name)) {
                             Ok(_) => {},
                             Err(err) => eprintln!("{}", err)
                         },
@@ -304,6 +704,22 @@ fn main() {
             }
         }
 
+        ("tag", Some(args)) => match (args.value_of("name"), args.value_of("tag")) {
+            (Some(name), Some(tag)) => match config.add_tag(name, tag) {
+                Ok(_) => println!("Tagged {} with {}", name, tag),
+                Err(err) => eprintln!("{}", err),
+            },
+            _ => eprintln!("A repo name and tag must both be given."),
+        },
+
+        ("untag", Some(args)) => match (args.value_of("name"), args.value_of("tag")) {
+            (Some(name), Some(tag)) => match config.remove_tag(name, tag) {
+                Ok(_) => println!("Removed tag {} from {}", tag, name),
+                Err(err) => eprintln!("{}", err),
+            },
+            _ => eprintln!("A repo name and tag must both be given."),
+        },
+
         ("remove", Some(args)) => match args.value_of("name") {
             Some(name) => {
                 match config.remove_repo(name) {
@@ -315,30 +731,168 @@ fn main() {
 
         ("list", Some(args)) => {
             if args.is_present("-v") || args.is_present("verbose") {
-                todo!("handle verbose output")
+                let filter_tag = args.value_of("tag");
+                let mut repos: Vec<&RepoData> = config
+                    .repos
+                    .values()
+                    .filter(|repo| match filter_tag {
+                        Some(tag) => repo.tags.iter().any(|t| t == tag),
+                        None => true,
+                    })
+                    .collect();
+
+                if repos.is_empty() {
+                    println!("No repos to show!");
+                } else {
+                    repos.sort_by(|a, b| a.name.cmp(&b.name));
+                    for repo in repos {
+                        println!(
+                            "{} : {} [{}]{}",
+                            repo.name,
+                            repo.path.display(),
+                            repo.backend,
+                            match &repo.remote {
+                                Some(remote) => format!(" <- {}", remote),
+                                None => String::new(),
+                            }
+                        );
+                        if !repo.tags.is_empty() {
+                            println!("  tags: {}", repo.tags.join(", "));
+                        }
+                    }
+                }
             } else {
-                match config.repos.is_empty() {
-                    true => println!("No repos to show!"),
-                    false => {
-                        for (key, val) in config.repos.iter() {
-                            println!("{} : {:?}", key, val);
+                let filter_tag = args.value_of("tag");
+                let mut repos: Vec<&RepoData> = config
+                    .repos
+                    .values()
+                    .filter(|repo| match filter_tag {
+                        Some(tag) => repo.tags.iter().any(|t| t == tag),
+                        None => true,
+                    })
+                    .collect();
+
+                if repos.is_empty() {
+                    println!("No repos to show!");
+                } else if let Some(tag) = filter_tag {
+                    println!("[{}]", tag);
+                    for repo in repos {
+                        println!("  {} : {:?}", repo.name, repo.path);
+                    }
+                } else {
+                    repos.sort_by(|a, b| a.name.cmp(&b.name));
+                    let mut by_tag: HashMap<&str, Vec<&RepoData>> = HashMap::new();
+                    for repo in &repos {
+                        if repo.tags.is_empty() {
+                            by_tag.entry("untagged").or_default().push(repo);
+                        } else {
+                            for tag in &repo.tags {
+                                by_tag.entry(tag.as_str()).or_default().push(repo);
+                            }
+                        }
+                    }
+                    let mut tags: Vec<&&str> = by_tag.keys().collect();
+                    tags.sort();
+                    for tag in tags {
+                        println!("[{}]", tag);
+                        for repo in &by_tag[tag] {
+                            println!("  {} : {:?}", repo.name, repo.path);
                         }
                     }
                 }
             }
         }
 
-        ("show", Some(args)) => {
-            if args.is_present("-v") || args.is_present("verbose") {
-                todo!("handle verbose output")
-            } else {
-                match args.value_of("name") {
-                    Some(name) => match config.get_repo(name) {
-                        Some(repo) => println!("{:?}", repo),
-                        None => eprintln!("No repo to show for name {}", name),
-                    },
-                    None => eprintln!("No repo name was passed!"),
+        ("show", Some(args)) => match args.value_of("name") {
+            Some(name) => match config.get_repo(name) {
+                Some(repo) => {
+                    println!("{} : {} [{}]", repo.name, repo.path.display(), repo.backend);
+                    if let Some(remote) = &repo.remote {
+                        println!("remote: {}", remote);
+                    }
+                    match repo.backend.as_str() {
+                        "git" => match git::status_porcelain(&repo.path) {
+                            Ok(status) => {
+                                println!("branch: {}", status.branch);
+                                println!(
+                                    "dirty: {} (staged {}, unstaged {}, untracked {}, unmerged {})",
+                                    status.is_dirty(),
+                                    status.staged,
+                                    status.unstaged,
+                                    status.untracked,
+                                    status.unmerged
+                                );
+                                println!("ahead {} / behind {}", status.ahead, status.behind);
+                                if args.is_present("-v") || args.is_present("verbose") {
+                                    for path in &status.changed_paths {
+                                        println!("  {}", path);
+                                    }
+                                }
+                            }
+                            Err(err) => eprintln!("{}", err),
+                        },
+                        other => eprintln!("No status query implemented yet for backend '{}'", other),
+                    }
                 }
+                None => eprintln!("No repo to show for name {}", name),
+            },
+            None => eprintln!("No repo name was passed!"),
+        },
+
+        ("clone", Some(args)) => match args.value_of("url") {
+            Some(url) => match repo_name_from_url(url) {
+                Ok(inferred_name) => {
+                    let name = args.value_of("alias").unwrap_or(&inferred_name).to_string();
+                    let dest = args
+                        .value_of("path")
+                        .map(PathBuf::from)
+                        .unwrap_or_else(|| PathBuf::from(&name));
+
+                    match git::clone(url, &dest) {
+                        Ok(_) => match dest.canonicalize() {
+                            Ok(abs_dest) => match config.add_repo(RepoData::new_cloned(&name, abs_dest, url)) {
+                                Ok(_) => {}
+                                Err(err) => eprintln!("{}", err),
+                            },
+                            Err(err) => eprintln!("{}", err),
+                        },
+                        Err(err) => eprintln!("{}", err),
+                    }
+                }
+                Err(err) => eprintln!("{}", err),
+            },
+            None => eprintln!("No URL was given to clone."),
+        },
+
+        ("exec", Some(args)) => {
+            let name_filter = args.value_of("name");
+            let tag_filter = args.value_of("tag");
+            let jobs: usize = args
+                .value_of("jobs")
+                .and_then(|j| j.parse().ok())
+                .unwrap_or(4);
+
+            let repos: Vec<(String, PathBuf)> = config
+                .repos
+                .values()
+                .filter(|repo| name_filter.is_none_or(|n| repo.name == n))
+                .filter(|repo| tag_filter.is_none_or(|t| repo.tags.iter().any(|tag| tag == t)))
+                .map(|repo| (repo.name.clone(), repo.path.clone()))
+                .collect();
+
+            match args.values_of("cmd") {
+                Some(mut cmd_parts) => match cmd_parts.next() {
+                    Some(cmd) => {
+                        let cmd_args: Vec<String> = cmd_parts.map(str::to_string).collect();
+                        if repos.is_empty() {
+                            println!("No repos match the given filters!");
+                        } else {
+                            exec_on_repos(repos, cmd, &cmd_args, jobs);
+                        }
+                    }
+                    None => eprintln!("No command was given to exec."),
+                },
+                None => eprintln!("No command was given to exec."),
             }
         }
 